@@ -1,4 +1,4 @@
-use super::delimited::{from_delimited_data, trim_from_str};
+use super::delimited::{from_delimited_data, trim_from_str, DelimitedReaderConfig};
 
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
@@ -32,6 +32,17 @@ impl Command for FromCsv {
                 "drop leading and trailing whitespaces around headers names and/or field values",
                 Some('t'),
             )
+            .named(
+                "comment",
+                SyntaxShape::String,
+                "a character to mark a line as a comment, skipping it when parsing",
+                Some('c'),
+            )
+            .switch(
+                "flexible",
+                "allow ragged rows with differing numbers of fields",
+                Some('f'),
+            )
             .category(Category::Formats)
     }
 
@@ -86,6 +97,16 @@ impl Command for FromCsv {
                 example: "open data.txt | from csv --trim fields",
                 result: None,
             },
+            Example {
+                description: "Convert comma-separated data to a table, ignoring lines starting with '#'",
+                example: "open data.txt | from csv --comment '#'",
+                result: None,
+            },
+            Example {
+                description: "Convert comma-separated data to a table, allowing rows with a different number of fields",
+                example: "open data.txt | from csv --flexible",
+                result: None,
+            },
         ]
     }
 }
@@ -101,7 +122,7 @@ fn from_csv(
     let noheaders = call.has_flag("noheaders");
     let separator: Option<Value> = call.get_flag(engine_state, stack, "separator")?;
     let trim: Option<Value> = call.get_flag(engine_state, stack, "trim")?;
-    let config = engine_state.get_config();
+    let comment: Option<Value> = call.get_flag(engine_state, stack, "comment")?;
 
     let sep = match separator {
         Some(Value::String { val: s, span }) => {
@@ -121,9 +142,33 @@ fn from_csv(
         _ => ',',
     };
 
+    let comment = match comment {
+        Some(Value::String { val: s, span }) => {
+            let vec_s: Vec<char> = s.chars().collect();
+            if vec_s.len() != 1 {
+                return Err(ShellError::MissingParameter(
+                    "single character comment".into(),
+                    span,
+                ));
+            };
+            Some(vec_s[0])
+        }
+        _ => None,
+    };
+
     let trim = trim_from_str(trim)?;
+    let flexible = call.has_flag("flexible");
+
+    let config = DelimitedReaderConfig {
+        separator: sep,
+        noheaders,
+        trim,
+        comment,
+        flexible,
+        ..Default::default()
+    };
 
-    from_delimited_data(noheaders, sep, trim, input, name, config)
+    from_delimited_data(config, input, name, engine_state.get_config())
 }
 
 #[cfg(test)]
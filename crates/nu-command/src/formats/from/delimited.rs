@@ -0,0 +1,157 @@
+use csv::ReaderBuilder;
+use indexmap::IndexMap;
+use nu_protocol::{Config, PipelineData, ShellError, Span, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trim {
+    All,
+    Headers,
+    Fields,
+    None,
+}
+
+pub fn trim_from_str(trim: Option<Value>) -> Result<Trim, ShellError> {
+    match trim {
+        Some(Value::String { val: s, span }) => match s.as_str() {
+            "all" => Ok(Trim::All),
+            "headers" => Ok(Trim::Headers),
+            "fields" => Ok(Trim::Fields),
+            "none" => Ok(Trim::None),
+            _ => Err(ShellError::MissingParameter(
+                "Trim must be one of: all, headers, fields, none".into(),
+                span,
+            )),
+        },
+        Some(_) => Ok(Trim::None),
+        None => Ok(Trim::None),
+    }
+}
+
+fn trim_field_value(field: &str, trim: Trim, is_header: bool) -> String {
+    match trim {
+        Trim::All => field.trim().to_string(),
+        Trim::Headers if is_header => field.trim().to_string(),
+        Trim::Fields if !is_header => field.trim().to_string(),
+        _ => field.to_string(),
+    }
+}
+
+/// Options accepted by the delimited-file readers (`from csv`, `from tsv`, ...).
+///
+/// Grouping these together keeps `from_delimited_data`'s signature stable as
+/// more reader options are added, rather than growing a long parameter list.
+#[derive(Debug, Clone)]
+pub struct DelimitedReaderConfig {
+    pub separator: char,
+    pub noheaders: bool,
+    pub trim: Trim,
+    pub comment: Option<char>,
+    pub quote: char,
+    pub escape: Option<char>,
+    pub flexible: bool,
+}
+
+impl Default for DelimitedReaderConfig {
+    fn default() -> Self {
+        Self {
+            separator: ',',
+            noheaders: false,
+            trim: Trim::None,
+            comment: None,
+            quote: '"',
+            escape: None,
+            flexible: false,
+        }
+    }
+}
+
+fn from_delimited_string_to_value(
+    s: String,
+    config: &DelimitedReaderConfig,
+    span: Span,
+) -> Result<Value, csv::Error> {
+    let mut reader_builder = ReaderBuilder::new();
+    reader_builder
+        .has_headers(false)
+        .delimiter(config.separator as u8)
+        .quote(config.quote as u8)
+        .flexible(config.flexible);
+    if let Some(comment) = config.comment {
+        reader_builder.comment(Some(comment as u8));
+    }
+    if let Some(escape) = config.escape {
+        reader_builder.escape(Some(escape as u8));
+    }
+    let mut reader = reader_builder.from_reader(s.as_bytes());
+
+    let records = reader.records().collect::<Result<Vec<_>, csv::Error>>()?;
+
+    let headers = if !records.is_empty() {
+        if config.noheaders {
+            (1..=records[0].len())
+                .map(|i| format!("column{}", i))
+                .collect::<Vec<String>>()
+        } else {
+            records[0]
+                .iter()
+                .map(|s| trim_field_value(s, config.trim, true))
+                .collect::<Vec<String>>()
+        }
+    } else {
+        Vec::new()
+    };
+
+    let first_row = usize::from(!config.noheaders);
+    let mut rows = vec![];
+
+    for row in records.iter().skip(first_row) {
+        let mut cols = IndexMap::new();
+        let mut fields = row.iter();
+
+        for header in headers.iter() {
+            let value = match fields.next() {
+                Some(value) => Value::string(trim_field_value(value, config.trim, false), span),
+                None if config.flexible => Value::Nothing { span },
+                None => break,
+            };
+            cols.insert(header.clone(), value);
+        }
+
+        if config.flexible {
+            let overflow: Vec<Value> = fields
+                .map(|value| Value::string(trim_field_value(value, config.trim, false), span))
+                .collect();
+            if !overflow.is_empty() {
+                cols.insert("extra".to_string(), Value::List { vals: overflow, span });
+            }
+        }
+
+        rows.push(Value::Record {
+            cols: cols.keys().cloned().collect(),
+            vals: cols.values().cloned().collect(),
+            span,
+        });
+    }
+
+    Ok(Value::List { vals: rows, span })
+}
+
+pub fn from_delimited_data(
+    config: DelimitedReaderConfig,
+    input: PipelineData,
+    name: Span,
+    engine_config: &Config,
+) -> Result<PipelineData, ShellError> {
+    let concat_string = input.collect_string("", engine_config)?;
+
+    Ok(
+        from_delimited_string_to_value(concat_string, &config, name)
+            .map_err(|x| {
+                ShellError::DelimiterError(
+                    format!("Could not parse as delimited file ({})", x),
+                    name,
+                )
+            })?
+            .into_pipeline_data(),
+    )
+}
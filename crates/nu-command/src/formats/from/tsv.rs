@@ -1,4 +1,4 @@
-use super::delimited::{from_delimited_data, trim_from_str};
+use super::delimited::{from_delimited_data, trim_from_str, DelimitedReaderConfig};
 
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
@@ -15,6 +15,12 @@ impl Command for FromTsv {
 
     fn signature(&self) -> Signature {
         Signature::build("from tsv")
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "a character to separate columns, defaults to '\\t'",
+                Some('s'),
+            )
             .switch(
                 "noheaders",
                 "don't treat the first row as column names",
@@ -26,6 +32,17 @@ impl Command for FromTsv {
                 "drop leading and trailing whitespaces around headers names and/or field values",
                 Some('t'),
             )
+            .named(
+                "comment",
+                SyntaxShape::String,
+                "a character to mark a line as a comment, skipping it when parsing",
+                Some('c'),
+            )
+            .switch(
+                "flexible",
+                "allow ragged rows with differing numbers of fields",
+                Some('f'),
+            )
             .category(Category::Formats)
     }
 
@@ -70,6 +87,21 @@ impl Command for FromTsv {
                 example: r#"echo $'a1(char tab)b1(char tab)c1(char nl)a2(char tab)b2(char tab)c2' | save tsv-data | open tsv-data | from tsv --trim fields"#,
                 result: None,
             },
+            Example {
+                description: "Create a tsv file with header columns and open it, ignoring lines starting with '#'",
+                example: r#"echo $'c1(char tab)c2(char tab)c3(char nl)#comment(char nl)1(char tab)2(char tab)3' | save tsv-data | open tsv-data | from tsv --comment '#'"#,
+                result: None,
+            },
+            Example {
+                description: "Create a tsv file with header columns and open it, allowing rows with a different number of fields",
+                example: r#"echo $'c1(char tab)c2(char tab)c3(char nl)1(char tab)2' | save tsv-data | open tsv-data | from tsv --flexible"#,
+                result: None,
+            },
+            Example {
+                description: "Convert semicolon-separated data to a table",
+                example: "open data.txt | from tsv --separator ';'",
+                result: None,
+            },
         ]
     }
 }
@@ -83,17 +115,54 @@ fn from_tsv(
     let name = call.head;
 
     let noheaders = call.has_flag("noheaders");
+    let separator: Option<Value> = call.get_flag(engine_state, stack, "separator")?;
     let trim: Option<Value> = call.get_flag(engine_state, stack, "trim")?;
     let trim = trim_from_str(trim)?;
+    let comment: Option<Value> = call.get_flag(engine_state, stack, "comment")?;
+    let comment = match comment {
+        Some(Value::String { val: s, span }) => {
+            let vec_s: Vec<char> = s.chars().collect();
+            if vec_s.len() != 1 {
+                return Err(ShellError::MissingParameter(
+                    "single character comment".into(),
+                    span,
+                ));
+            };
+            Some(vec_s[0])
+        }
+        _ => None,
+    };
+
+    let flexible = call.has_flag("flexible");
 
-    from_delimited_data(
+    let sep = match separator {
+        Some(Value::String { val: s, span }) => {
+            if s == r"\t" {
+                '\t'
+            } else {
+                let vec_s: Vec<char> = s.chars().collect();
+                if vec_s.len() != 1 {
+                    return Err(ShellError::MissingParameter(
+                        "single character separator".into(),
+                        span,
+                    ));
+                };
+                vec_s[0]
+            }
+        }
+        _ => '\t',
+    };
+
+    let config = DelimitedReaderConfig {
+        separator: sep,
         noheaders,
-        '\t',
         trim,
-        input,
-        name,
-        engine_state.get_config(),
-    )
+        comment,
+        flexible,
+        ..Default::default()
+    };
+
+    from_delimited_data(config, input, name, engine_state.get_config())
 }
 
 #[cfg(test)]